@@ -3,11 +3,12 @@
 use data_encoding::{Encoding, Specification};
 use once_cell::sync::Lazy;
 use pyo3::basic::CompareOp;
+use pyo3::create_exception;
 use pyo3::exceptions::PyValueError;
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::pycell::PyRef;
-use pyo3::types::{PyAny, PyBytes, PyDict, PyModule, PyString, PyType};
+use pyo3::pycell::{PyCell, PyRef};
+use pyo3::types::{PyAny, PyBytes, PyDict, PyList, PyModule, PyString, PyTuple, PyType};
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 use uuid::Uuid;
@@ -16,6 +17,7 @@ use uuid::Uuid;
 pub enum CrockfordError {
     InvalidLength(usize),
     DecodeError(data_encoding::DecodeError),
+    ChecksumMismatch { expected: char, found: char },
 }
 
 impl std::fmt::Display for CrockfordError {
@@ -23,6 +25,10 @@ impl std::fmt::Display for CrockfordError {
         match self {
             CrockfordError::InvalidLength(len) => write!(f, "expected 16 bytes, got {len}"),
             CrockfordError::DecodeError(e) => write!(f, "{e}"),
+            CrockfordError::ChecksumMismatch { expected, found } => write!(
+                f,
+                "checksum mismatch: expected '{expected}', found '{found}'"
+            ),
         }
     }
 }
@@ -46,6 +52,51 @@ fn uuid_module(py: Python<'_>) -> PyResult<&PyModule> {
     Ok(module.as_ref(py))
 }
 
+// Cache the 'datetime' module to avoid repeated imports at runtime.
+fn datetime_module(py: Python<'_>) -> PyResult<&PyModule> {
+    static DATETIME_MODULE: GILOnceCell<Py<PyModule>> = GILOnceCell::new();
+    let module = DATETIME_MODULE
+        .get_or_try_init(py, || PyModule::import(py, "datetime").map(|m| m.into()))?;
+    Ok(module.as_ref(py))
+}
+
+// The number of 100ns intervals between the Gregorian epoch (1582-10-15)
+// and the Unix epoch (1970-01-01), as used by RFC 4122 v1 timestamps.
+const GREGORIAN_TO_UNIX_100NS: u64 = 122_192_928_000_000_000;
+
+/// RFC 4122 version nibble, read from byte 6 of a 16-byte UUID.
+pub fn uuid_version(bytes: &[u8; 16]) -> u8 {
+    bytes[6] >> 4
+}
+
+/// Unix-milliseconds timestamp embedded in a v7 UUID's high 48 bits.
+pub fn v7_timestamp_ms(bytes: &[u8; 16]) -> u64 {
+    let mut ms_bytes = [0u8; 8];
+    ms_bytes[2..].copy_from_slice(&bytes[0..6]);
+    u64::from_be_bytes(ms_bytes)
+}
+
+/// Unix-seconds timestamp decoded from a v1 UUID's 60-bit Gregorian
+/// timestamp (`time_low` | `time_mid` | low 12 bits of `time_hi_and_version`).
+pub fn v1_timestamp_seconds(bytes: &[u8; 16]) -> f64 {
+    let time_low = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as u64;
+    let time_mid = u16::from_be_bytes([bytes[4], bytes[5]]) as u64;
+    let time_hi = (u16::from_be_bytes([bytes[6], bytes[7]]) & 0x0FFF) as u64;
+    let gregorian_100ns = (time_hi << 48) | (time_mid << 32) | time_low;
+    let unix_100ns = gregorian_100ns as i64 - GREGORIAN_TO_UNIX_100NS as i64;
+    unix_100ns as f64 / 10_000_000.0
+}
+
+/// Embedded timestamp in Unix seconds, for the time-based UUID versions.
+/// Returns `Err` with the version number for any other version.
+fn timestamp_seconds(bytes: &[u8; 16]) -> Result<f64, u8> {
+    match uuid_version(bytes) {
+        7 => Ok(v7_timestamp_ms(bytes) as f64 / 1000.0),
+        1 => Ok(v1_timestamp_seconds(bytes)),
+        v => Err(v),
+    }
+}
+
 pub fn encode_bytes_to_crockford(bytes: &[u8; 16]) -> String {
     CROCKFORD.encode(bytes)
 }
@@ -62,21 +113,245 @@ pub fn decode_crockford_to_bytes(s: &str) -> Result<[u8; 16], CrockfordError> {
     Ok(out)
 }
 
+// Crockford's optional check symbol: the 32-symbol alphabet extended with
+// `*~$=U` for remainders 32-36 of the mod-37 checksum.
+const CHECK_SYMBOLS: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+
+fn checksum_symbol(bytes: &[u8; 16]) -> char {
+    let value = u128::from_be_bytes(*bytes);
+    let remainder = (value % 37) as usize;
+    CHECK_SYMBOLS.chars().nth(remainder).unwrap()
+}
+
+pub fn encode_bytes_to_crockford_checked(bytes: &[u8; 16], checksum: bool) -> String {
+    let mut out = encode_bytes_to_crockford(bytes);
+    if checksum {
+        out.push(checksum_symbol(bytes));
+    }
+    out
+}
+
+pub fn decode_crockford_to_bytes_checked(
+    s: &str,
+    checksum: bool,
+) -> Result<[u8; 16], CrockfordError> {
+    if !checksum {
+        return decode_crockford_to_bytes(s);
+    }
+    let mut chars: Vec<char> = s.chars().filter(|c| *c != '-').collect();
+    let found = chars
+        .pop()
+        .ok_or(CrockfordError::InvalidLength(0))?
+        .to_ascii_uppercase();
+    let body: String = chars.into_iter().collect();
+    let bytes = decode_crockford_to_bytes(&body)?;
+    let expected = checksum_symbol(&bytes);
+    if found != expected {
+        return Err(CrockfordError::ChecksumMismatch { expected, found });
+    }
+    Ok(bytes)
+}
+
+/// RFC 4122 v3 (MD5) name-based UUID, generated from a namespace UUID and a name.
+pub fn generate_namespaced_v3(namespace: &Uuid, name: &[u8]) -> Uuid {
+    Uuid::new_v3(namespace, name)
+}
+
+/// RFC 4122 v5 (SHA-1) name-based UUID, generated from a namespace UUID and a name.
+pub fn generate_namespaced_v5(namespace: &Uuid, name: &[u8]) -> Uuid {
+    Uuid::new_v5(namespace, name)
+}
+
+// Accepts a namespace as a CrockfordUUID, a uuid.UUID, or one of the
+// NAMESPACE_* module constants (which are themselves CrockfordUUID instances).
+fn namespace_to_uuid(value: &PyAny) -> PyResult<Uuid> {
+    if let Ok(inner) = value.downcast::<PyCell<CrockfordUUID>>() {
+        return Ok(Uuid::from_bytes(inner.borrow().bytes));
+    }
+    let uuid_mod = uuid_module(value.py())?;
+    if value.is_instance(uuid_mod.getattr("UUID")?)? {
+        let py_bytes: &PyBytes = value.getattr("bytes")?.extract()?;
+        let mut arr = [0u8; 16];
+        arr.copy_from_slice(py_bytes.as_bytes());
+        return Ok(Uuid::from_bytes(arr));
+    }
+    Err(PyValueError::new_err(
+        "namespace must be a CrockfordUUID or uuid.UUID",
+    ))
+}
+
+/// A standard canonical UUID string has hyphens at fixed positions
+/// (8-4-4-4-12); Crockford tokens have no fixed hyphen placement, so this
+/// is enough to tell the two textual forms apart before parsing.
+pub fn looks_like_hyphenated_uuid(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    bytes.len() == 36
+        && bytes[8] == b'-'
+        && bytes[13] == b'-'
+        && bytes[18] == b'-'
+        && bytes[23] == b'-'
+}
+
+fn name_to_bytes(value: &PyAny) -> PyResult<Vec<u8>> {
+    if let Ok(s) = value.downcast::<PyString>() {
+        return Ok(s.to_str()?.as_bytes().to_vec());
+    }
+    if let Ok(b) = value.downcast::<PyBytes>() {
+        return Ok(b.as_bytes().to_vec());
+    }
+    Err(PyValueError::new_err("name must be a str or bytes"))
+}
+
 #[pyfunction]
-fn encode_crockford_py(b: &[u8]) -> PyResult<String> {
+#[pyo3(signature = (b, checksum=false))]
+fn encode_crockford_py(b: &[u8], checksum: bool) -> PyResult<String> {
     if b.len() != 16 {
         return Err(PyValueError::new_err("input must be exactly 16 bytes"));
     }
     let arr: &[u8; 16] = b.try_into().unwrap();
-    Ok(encode_bytes_to_crockford(arr))
+    Ok(encode_bytes_to_crockford_checked(arr, checksum))
 }
 
 #[pyfunction]
-fn decode_crockford_py(py: Python<'_>, s: &str) -> PyResult<Py<PyBytes>> {
-    let bytes = decode_crockford_to_bytes(s).map_err(|e| PyValueError::new_err(e.to_string()))?;
+#[pyo3(signature = (s, checksum=false))]
+fn decode_crockford_py(py: Python<'_>, s: &str, checksum: bool) -> PyResult<Py<PyBytes>> {
+    let bytes = decode_crockford_to_bytes_checked(s, checksum)
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
     Ok(PyBytes::new(py, &bytes).into())
 }
 
+/// Encodes many 16-byte buffers at once, doing the Rust-side work with the
+/// GIL released so a large batch doesn't hold up other threads.
+#[pyfunction]
+fn encode_crockford_batch(py: Python<'_>, items: Vec<Vec<u8>>) -> PyResult<Vec<String>> {
+    for (i, item) in items.iter().enumerate() {
+        if item.len() != 16 {
+            return Err(PyValueError::new_err(format!(
+                "item {i}: input must be exactly 16 bytes"
+            )));
+        }
+    }
+    Ok(py.allow_threads(|| {
+        items
+            .iter()
+            .map(|b| {
+                let arr: &[u8; 16] = b.as_slice().try_into().unwrap();
+                encode_bytes_to_crockford(arr)
+            })
+            .collect()
+    }))
+}
+
+create_exception!(
+    _pycrockford_rs_bindings,
+    BatchDecodeError,
+    PyValueError,
+    "Raised by decode_crockford_batch when one or more items fail to decode.\n\n\
+     `args[1]` holds the successfully decoded bytes (with `None` at each\n\
+     failing position) and `args[2]` holds a list of `(index, message)`\n\
+     pairs for every failing item, so a single bad token doesn't discard the\n\
+     rest of the batch's results."
+);
+
+/// Decodes many Crockford strings at once, doing the Rust-side work with
+/// the GIL released. Every item is decoded regardless of earlier failures;
+/// if any fail, raises `BatchDecodeError` carrying the successful results
+/// and the full list of failing indices rather than discarding the batch.
+#[pyfunction]
+fn decode_crockford_batch(py: Python<'_>, items: Vec<String>) -> PyResult<Vec<Py<PyBytes>>> {
+    let outcomes: Vec<Result<[u8; 16], String>> = py.allow_threads(|| {
+        items
+            .iter()
+            .map(|s| decode_crockford_to_bytes(s).map_err(|e| e.to_string()))
+            .collect()
+    });
+
+    let errors: Vec<(usize, String)> = outcomes
+        .iter()
+        .enumerate()
+        .filter_map(|(i, r)| r.as_ref().err().map(|e| (i, e.clone())))
+        .collect();
+
+    if !errors.is_empty() {
+        let result_objs: Vec<PyObject> = outcomes
+            .iter()
+            .map(|r| match r {
+                Ok(bytes) => {
+                    let py_bytes: Py<PyBytes> = PyBytes::new(py, bytes).into();
+                    py_bytes.into_py(py)
+                }
+                Err(_) => py.None(),
+            })
+            .collect();
+        let results: Py<PyList> = PyList::new(py, result_objs).into();
+
+        let error_objs: Vec<PyObject> = errors
+            .iter()
+            .map(|(idx, msg)| {
+                let tuple: Py<PyTuple> =
+                    PyTuple::new(py, [idx.to_object(py), msg.to_object(py)]).into();
+                tuple.into_py(py)
+            })
+            .collect();
+        let error_list: Py<PyList> = PyList::new(py, error_objs).into();
+
+        let message = format!(
+            "{} of {} item(s) failed to decode: {}",
+            errors.len(),
+            items.len(),
+            errors
+                .iter()
+                .map(|(i, e)| format!("item {i}: {e}"))
+                .collect::<Vec<_>>()
+                .join("; ")
+        );
+        return Err(BatchDecodeError::new_err((
+            message,
+            results.into_py(py),
+            error_list.into_py(py),
+        )));
+    }
+
+    Ok(outcomes
+        .into_iter()
+        .map(|r| PyBytes::new(py, &r.unwrap()).into())
+        .collect())
+}
+
+/// `msgspec.json.Encoder`/`msgspec.msgpack.Encoder` `enc_hook` for `CrockfordUUID`.
+///
+/// Pass this directly as `enc_hook=crockford.enc_hook`; it encodes a
+/// `CrockfordUUID` to its Crockford string representation.
+#[pyfunction]
+fn enc_hook(py: Python<'_>, obj: &PyAny) -> PyResult<PyObject> {
+    if let Ok(value) = obj.downcast::<PyCell<CrockfordUUID>>() {
+        return Ok(PyString::new(py, &value.borrow().__str__()).into());
+    }
+    Err(PyValueError::new_err(format!(
+        "enc_hook does not support type {}",
+        obj.get_type().name()?
+    )))
+}
+
+/// `msgspec.json.Decoder`/`msgspec.msgpack.Decoder` `dec_hook` for `CrockfordUUID`.
+///
+/// Pass this directly as `dec_hook=crockford.dec_hook`; it reconstructs a
+/// `CrockfordUUID` from its Crockford string representation whenever
+/// `type is CrockfordUUID`.
+#[pyfunction]
+fn dec_hook(py: Python<'_>, typ: &PyType, value: &PyAny) -> PyResult<PyObject> {
+    if typ.is(py.get_type::<CrockfordUUID>()) {
+        let s: &str = value.extract()?;
+        let bytes =
+            decode_crockford_to_bytes(s).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        return Ok(Py::new(py, CrockfordUUID { bytes })?.into_py(py));
+    }
+    Err(PyValueError::new_err(format!(
+        "dec_hook does not support type {}",
+        typ.name()?
+    )))
+}
+
 #[pyclass]
 struct CrockfordUUID {
     bytes: [u8; 16],
@@ -86,10 +361,18 @@ struct CrockfordUUID {
 #[allow(non_local_definitions)]
 impl CrockfordUUID {
     #[new]
-    fn new(value: &PyAny) -> PyResult<Self> {
+    #[pyo3(signature = (value, checksum=false))]
+    fn new(value: &PyAny, checksum: bool) -> PyResult<Self> {
         if let Ok(s) = value.downcast::<PyString>() {
-            let bytes = decode_crockford_to_bytes(s.to_str()?)
-                .map_err(|e| PyValueError::new_err(e.to_string()))?;
+            let s = s.to_str()?;
+            let bytes = if looks_like_hyphenated_uuid(s) {
+                Uuid::parse_str(s)
+                    .map(|u| *u.as_bytes())
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?
+            } else {
+                decode_crockford_to_bytes_checked(s, checksum)
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?
+            };
             Ok(Self { bytes })
         } else if let Ok(b) = value.downcast::<PyBytes>() {
             let slice = b.as_bytes();
@@ -133,6 +416,64 @@ impl CrockfordUUID {
         encode_bytes_to_crockford(&self.bytes)
     }
 
+    /// Crockford text, optionally with a trailing mod-37 check symbol.
+    #[pyo3(signature = (checksum=false))]
+    fn to_string(&self, checksum: bool) -> String {
+        encode_bytes_to_crockford_checked(&self.bytes, checksum)
+    }
+
+    /// Canonical `8-4-4-4-12` hyphenated representation.
+    fn hyphenated(&self) -> String {
+        Uuid::from_bytes(self.bytes).hyphenated().to_string()
+    }
+
+    /// 32-character lowercase hex representation, no hyphens.
+    #[getter]
+    fn hex(&self) -> String {
+        Uuid::from_bytes(self.bytes).simple().to_string()
+    }
+
+    /// `urn:uuid:` URN representation.
+    #[getter]
+    fn urn(&self) -> String {
+        Uuid::from_bytes(self.bytes).urn().to_string()
+    }
+
+    /// RFC 4122 version nibble (e.g. `4`, `7`).
+    #[getter]
+    fn version(&self) -> u8 {
+        uuid_version(&self.bytes)
+    }
+
+    /// Embedded timestamp as Unix seconds (a `float`), for v1 and v7 UUIDs.
+    ///
+    /// Raises `ValueError` for any other version, which carries no
+    /// embedded timestamp.
+    #[getter]
+    fn timestamp(&self) -> PyResult<f64> {
+        timestamp_seconds(&self.bytes).map_err(|v| {
+            PyValueError::new_err(format!(
+                "timestamp is only defined for version 1 or 7 UUIDs, got version {v}"
+            ))
+        })
+    }
+
+    /// Embedded timestamp as a timezone-aware UTC `datetime.datetime`.
+    #[getter]
+    fn datetime(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let seconds = timestamp_seconds(&self.bytes).map_err(|v| {
+            PyValueError::new_err(format!(
+                "datetime is only defined for version 1 or 7 UUIDs, got version {v}"
+            ))
+        })?;
+        let datetime_mod = datetime_module(py)?;
+        let utc = datetime_mod.getattr("timezone")?.getattr("utc")?;
+        datetime_mod
+            .getattr("datetime")?
+            .call_method1("fromtimestamp", (seconds, utc))
+            .map(Into::into)
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "CrockfordUUID('{}')",
@@ -169,12 +510,73 @@ impl CrockfordUUID {
             bytes: *uuid.as_bytes(),
         }
     }
+
+    #[classmethod]
+    fn generate_v3(_cls: &PyType, namespace: &PyAny, name: &PyAny) -> PyResult<Self> {
+        let namespace = namespace_to_uuid(namespace)?;
+        let name = name_to_bytes(name)?;
+        let uuid = generate_namespaced_v3(&namespace, &name);
+        Ok(Self {
+            bytes: *uuid.as_bytes(),
+        })
+    }
+
+    #[classmethod]
+    fn generate_v5(_cls: &PyType, namespace: &PyAny, name: &PyAny) -> PyResult<Self> {
+        let namespace = namespace_to_uuid(namespace)?;
+        let name = name_to_bytes(name)?;
+        let uuid = generate_namespaced_v5(&namespace, &name);
+        Ok(Self {
+            bytes: *uuid.as_bytes(),
+        })
+    }
 }
 
 #[pymodule]
-fn _pycrockford_rs_bindings(_py: Python, m: &PyModule) -> PyResult<()> {
+fn _pycrockford_rs_bindings(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(decode_crockford_py, m)?)?;
     m.add_function(wrap_pyfunction!(encode_crockford_py, m)?)?;
+    m.add_function(wrap_pyfunction!(enc_hook, m)?)?;
+    m.add_function(wrap_pyfunction!(dec_hook, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_crockford_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_crockford_batch, m)?)?;
+    m.add("BatchDecodeError", py.get_type::<BatchDecodeError>())?;
     m.add_class::<CrockfordUUID>()?;
+    m.add(
+        "NAMESPACE_DNS",
+        Py::new(
+            py,
+            CrockfordUUID {
+                bytes: *Uuid::NAMESPACE_DNS.as_bytes(),
+            },
+        )?,
+    )?;
+    m.add(
+        "NAMESPACE_URL",
+        Py::new(
+            py,
+            CrockfordUUID {
+                bytes: *Uuid::NAMESPACE_URL.as_bytes(),
+            },
+        )?,
+    )?;
+    m.add(
+        "NAMESPACE_OID",
+        Py::new(
+            py,
+            CrockfordUUID {
+                bytes: *Uuid::NAMESPACE_OID.as_bytes(),
+            },
+        )?,
+    )?;
+    m.add(
+        "NAMESPACE_X500",
+        Py::new(
+            py,
+            CrockfordUUID {
+                bytes: *Uuid::NAMESPACE_X500.as_bytes(),
+            },
+        )?,
+    )?;
     Ok(())
 }
@@ -1,6 +1,10 @@
 use _pycrockford_rs_bindings::{
-    decode_crockford_to_bytes, encode_bytes_to_crockford, CrockfordError,
+    decode_crockford_to_bytes, decode_crockford_to_bytes_checked, encode_bytes_to_crockford,
+    encode_bytes_to_crockford_checked, generate_namespaced_v3, generate_namespaced_v5,
+    looks_like_hyphenated_uuid, uuid_version, v1_timestamp_seconds, v7_timestamp_ms,
+    CrockfordError,
 };
+use uuid::Uuid;
 
 #[test]
 fn round_trip() {
@@ -29,3 +33,117 @@ fn decode_is_case_insensitive() {
     let decoded = decode_crockford_to_bytes(&encoded.to_lowercase()).unwrap();
     assert_eq!(decoded, bytes);
 }
+
+#[test]
+fn checksum_round_trip() {
+    let bytes = [7u8; 16];
+    let encoded = encode_bytes_to_crockford_checked(&bytes, true);
+    assert_eq!(encoded.len(), 27);
+    let decoded = decode_crockford_to_bytes_checked(&encoded, true).unwrap();
+    assert_eq!(decoded, bytes);
+}
+
+#[test]
+fn checksum_detects_tampering() {
+    let bytes = [7u8; 16];
+    let mut encoded = encode_bytes_to_crockford_checked(&bytes, true);
+    let last = encoded.pop().unwrap();
+    let replacement = if last == '0' { '1' } else { '0' };
+    encoded.push(replacement);
+    let err = decode_crockford_to_bytes_checked(&encoded, true).unwrap_err();
+    assert!(matches!(err, CrockfordError::ChecksumMismatch { .. }));
+}
+
+#[test]
+fn unchecked_decode_ignores_checksum_flag_when_absent() {
+    let bytes = [9u8; 16];
+    let encoded = encode_bytes_to_crockford(&bytes);
+    let decoded = decode_crockford_to_bytes_checked(&encoded, false).unwrap();
+    assert_eq!(decoded, bytes);
+}
+
+#[test]
+fn uuid_version_reads_high_nibble_of_byte_six() {
+    let mut bytes = [0u8; 16];
+    bytes[6] = 0x70;
+    assert_eq!(uuid_version(&bytes), 7);
+}
+
+#[test]
+fn v7_timestamp_round_trips_milliseconds() {
+    let ms: u64 = 1_700_000_000_123;
+    let mut bytes = [0u8; 16];
+    bytes[0..6].copy_from_slice(&ms.to_be_bytes()[2..8]);
+    bytes[6] = 0x70;
+    assert_eq!(v7_timestamp_ms(&bytes), ms);
+}
+
+#[test]
+fn v1_timestamp_decodes_gregorian_epoch_as_unix_epoch() {
+    // time_low/time_mid/time_hi for a 60-bit Gregorian timestamp equal to
+    // exactly GREGORIAN_TO_UNIX_100NS (122_192_928_000_000_000), i.e. the
+    // Unix epoch itself, so the decoded value should be 0.0 seconds.
+    let bytes = [
+        0x13, 0x81, 0x40, 0x00, 0x1d, 0xd2, 0x11, 0xb2, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    assert_eq!(uuid_version(&bytes), 1);
+    assert_eq!(v1_timestamp_seconds(&bytes), 0.0);
+}
+
+#[test]
+fn generate_v3_matches_rfc4122_test_vector() {
+    let uuid = generate_namespaced_v3(&Uuid::NAMESPACE_DNS, b"python.org");
+    assert_eq!(uuid.to_string(), "6fa459ea-ee8a-3ca4-894e-db77e160355e");
+}
+
+#[test]
+fn generate_v5_matches_rfc4122_test_vector() {
+    let uuid = generate_namespaced_v5(&Uuid::NAMESPACE_DNS, b"python.org");
+    assert_eq!(uuid.to_string(), "886313e1-3b8a-5372-9b90-0c9aee199e5d");
+}
+
+#[test]
+fn generate_v3_and_v5_are_deterministic_and_distinct() {
+    let v3_again = generate_namespaced_v3(&Uuid::NAMESPACE_DNS, b"python.org");
+    let v5 = generate_namespaced_v5(&Uuid::NAMESPACE_DNS, b"python.org");
+    assert_eq!(
+        generate_namespaced_v3(&Uuid::NAMESPACE_DNS, b"python.org"),
+        v3_again
+    );
+    assert_ne!(v3_again, v5);
+}
+
+#[test]
+fn looks_like_hyphenated_uuid_accepts_canonical_form() {
+    assert!(looks_like_hyphenated_uuid(
+        "550e8400-e29b-41d4-a716-446655440000"
+    ));
+}
+
+#[test]
+fn looks_like_hyphenated_uuid_rejects_36_char_crockford_like_token() {
+    // Same length as a canonical UUID string, but without hyphens at the
+    // canonical 8-13-18-23 positions: must route to the Crockford parser,
+    // not be misdetected as a hyphenated UUID.
+    let token = "0".repeat(36);
+    assert!(!looks_like_hyphenated_uuid(&token));
+}
+
+#[test]
+fn looks_like_hyphenated_uuid_rejects_wrong_length() {
+    assert!(!looks_like_hyphenated_uuid("550e8400-e29b-41d4-a716"));
+}
+
+#[test]
+fn canonical_hyphenated_string_parses_to_expected_bytes() {
+    let s = "550e8400-e29b-41d4-a716-446655440000";
+    assert!(looks_like_hyphenated_uuid(s));
+    let uuid = Uuid::parse_str(s).unwrap();
+    assert_eq!(
+        *uuid.as_bytes(),
+        [
+            0x55, 0x0e, 0x84, 0x00, 0xe2, 0x9b, 0x41, 0xd4, 0xa7, 0x16, 0x44, 0x66, 0x55, 0x44,
+            0x00, 0x00,
+        ]
+    );
+}
@@ -0,0 +1,60 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// Drives `enc_hook`/`dec_hook` through real `msgspec.json`/`msgspec.msgpack`
+/// encoders and decoders, embedding a Python interpreter and registering the
+/// compiled extension into its builtin module table so `import
+/// _pycrockford_rs_bindings` resolves without installing the crate as a
+/// shared library.
+#[test]
+fn enc_hook_and_dec_hook_round_trip_through_msgspec_json_and_msgpack() {
+    pyo3::append_to_inittab!(_pycrockford_rs_bindings);
+    pyo3::prepare_freethreaded_python();
+
+    Python::with_gil(|py| {
+        let crockford = PyModule::import(py, "_pycrockford_rs_bindings")
+            .expect("the _pycrockford_rs_bindings extension must be importable");
+        let crockford_uuid_cls = crockford.getattr("CrockfordUUID").unwrap();
+        let original = crockford_uuid_cls.call_method0("generate_v4").unwrap();
+
+        let enc_hook = crockford.getattr("enc_hook").unwrap();
+        let dec_hook = crockford.getattr("dec_hook").unwrap();
+
+        let json_mod =
+            PyModule::import(py, "msgspec.json").expect("msgspec must be installed for this test");
+        let msgpack_mod = PyModule::import(py, "msgspec.msgpack").unwrap();
+
+        for (encoder_cls, decoder_cls) in [
+            (
+                json_mod.getattr("Encoder").unwrap(),
+                json_mod.getattr("Decoder").unwrap(),
+            ),
+            (
+                msgpack_mod.getattr("Encoder").unwrap(),
+                msgpack_mod.getattr("Decoder").unwrap(),
+            ),
+        ] {
+            let enc_kwargs = PyDict::new(py);
+            enc_kwargs.set_item("enc_hook", enc_hook).unwrap();
+            let encoder = encoder_cls.call((), Some(enc_kwargs)).unwrap();
+
+            let dec_kwargs = PyDict::new(py);
+            dec_kwargs.set_item("dec_hook", dec_hook).unwrap();
+            dec_kwargs.set_item("type", crockford_uuid_cls).unwrap();
+            let decoder = decoder_cls.call((), Some(dec_kwargs)).unwrap();
+
+            let encoded = encoder.call_method1("encode", (original,)).unwrap();
+            let decoded = decoder.call_method1("decode", (encoded,)).unwrap();
+
+            let equal: bool = original
+                .call_method1("__eq__", (decoded,))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert!(
+                equal,
+                "round trip through {encoder_cls:?} changed the value"
+            );
+        }
+    });
+}
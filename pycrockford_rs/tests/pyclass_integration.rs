@@ -0,0 +1,116 @@
+use pyo3::prelude::*;
+
+/// Drives `CrockfordUUID` itself (constructor, getters, classmethods) through
+/// an embedded interpreter, rather than re-testing the free functions it
+/// wraps against themselves.
+#[test]
+fn checksummed_string_round_trips_through_new() {
+    pyo3::append_to_inittab!(_pycrockford_rs_bindings);
+    pyo3::prepare_freethreaded_python();
+
+    Python::with_gil(|py| {
+        let crockford = PyModule::import(py, "_pycrockford_rs_bindings")
+            .expect("the _pycrockford_rs_bindings extension must be importable");
+        let crockford_uuid_cls = crockford.getattr("CrockfordUUID").unwrap();
+
+        // A string produced by to_string(checksum=True) must parse back
+        // through __new__ with checksum=True, and a tampered string must be
+        // rejected rather than silently accepted.
+        let original = crockford_uuid_cls.call_method0("generate_v4").unwrap();
+        let checked: String = original
+            .call_method1("to_string", (true,))
+            .unwrap()
+            .extract()
+            .unwrap();
+        let kwargs = pyo3::types::PyDict::new(py);
+        kwargs.set_item("checksum", true).unwrap();
+        let reparsed = crockford_uuid_cls
+            .call((checked.clone(),), Some(kwargs))
+            .unwrap();
+        let equal: bool = original
+            .call_method1("__eq__", (reparsed,))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(
+            equal,
+            "checksummed round trip through __new__ changed the value"
+        );
+
+        let mut tampered = checked;
+        let last = tampered.pop().unwrap();
+        tampered.push(if last == '0' { '1' } else { '0' });
+        let bad_kwargs = pyo3::types::PyDict::new(py);
+        bad_kwargs.set_item("checksum", true).unwrap();
+        assert!(
+            crockford_uuid_cls
+                .call((tampered,), Some(bad_kwargs))
+                .is_err(),
+            "tampered checksummed string must fail to parse with checksum=True"
+        );
+    });
+}
+
+/// Exercises the `__new__` hyphenated-vs-Crockford disambiguation and the
+/// `hyphenated()`/`hex`/`urn` adapters on a real `CrockfordUUID` instance,
+/// rather than re-deriving the same values directly from `uuid::Uuid`.
+#[test]
+fn hyphenated_string_parses_and_adapters_format_as_expected() {
+    pyo3::append_to_inittab!(_pycrockford_rs_bindings);
+    pyo3::prepare_freethreaded_python();
+
+    Python::with_gil(|py| {
+        let crockford = PyModule::import(py, "_pycrockford_rs_bindings")
+            .expect("the _pycrockford_rs_bindings extension must be importable");
+        let crockford_uuid_cls = crockford.getattr("CrockfordUUID").unwrap();
+
+        let canonical = "550e8400-e29b-41d4-a716-446655440000";
+        let from_hyphenated = crockford_uuid_cls.call1((canonical,)).unwrap();
+
+        let hyphenated: String = from_hyphenated
+            .call_method0("hyphenated")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(hyphenated, canonical);
+
+        let hex: String = from_hyphenated.getattr("hex").unwrap().extract().unwrap();
+        assert_eq!(hex, "550e8400e29b41d4a716446655440000");
+
+        let urn: String = from_hyphenated.getattr("urn").unwrap().extract().unwrap();
+        assert_eq!(urn, "urn:uuid:550e8400-e29b-41d4-a716-446655440000");
+    });
+}
+
+/// Exercises the `version`/`timestamp`/`datetime` getters on real
+/// `generate_v7()`/`generate_v4()` instances, including that `timestamp`
+/// raises for a version with no embedded timestamp.
+#[test]
+fn version_and_timestamp_getters_behave_per_uuid_version() {
+    pyo3::append_to_inittab!(_pycrockford_rs_bindings);
+    pyo3::prepare_freethreaded_python();
+
+    Python::with_gil(|py| {
+        let crockford = PyModule::import(py, "_pycrockford_rs_bindings")
+            .expect("the _pycrockford_rs_bindings extension must be importable");
+        let crockford_uuid_cls = crockford.getattr("CrockfordUUID").unwrap();
+
+        let v7 = crockford_uuid_cls.call_method0("generate_v7").unwrap();
+        let version: u8 = v7.getattr("version").unwrap().extract().unwrap();
+        assert_eq!(version, 7);
+        let timestamp: f64 = v7.getattr("timestamp").unwrap().extract().unwrap();
+        assert!(timestamp > 0.0);
+        let datetime = v7.getattr("datetime").unwrap();
+        assert!(!datetime.is_none());
+
+        let v4 = crockford_uuid_cls.call_method0("generate_v4").unwrap();
+        assert!(
+            v4.getattr("timestamp").is_err(),
+            "timestamp must raise for a v4 UUID"
+        );
+        assert!(
+            v4.getattr("datetime").is_err(),
+            "datetime must raise for a v4 UUID"
+        );
+    });
+}